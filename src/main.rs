@@ -1,11 +1,232 @@
 use colored::Colorize;
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use regex::Regex;
+use semver::{Version, VersionReq};
+use serde_json::Value;
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process;
 use walkdir::WalkDir;
 
+/// Package name -> resolved version, as recorded by a lockfile.
+type ResolvedVersions = HashMap<String, String>;
+
+/// Lockfile formats the cross-validation subsystem knows how to parse.
+enum LockfileFormat {
+    Npm,
+    Yarn,
+    Pnpm,
+    Bun,
+}
+
+fn lockfile_format_for(file_name: &str) -> Option<LockfileFormat> {
+    match file_name {
+        "package-lock.json" => Some(LockfileFormat::Npm),
+        "yarn.lock" => Some(LockfileFormat::Yarn),
+        "pnpm-lock.yaml" => Some(LockfileFormat::Pnpm),
+        "bun.lock" => Some(LockfileFormat::Bun),
+        _ => None,
+    }
+}
+
+/// Walk upward from `start_dir` looking for the nearest lockfile of any
+/// supported package manager.
+fn find_lockfile(start_dir: &Path) -> Option<(PathBuf, LockfileFormat)> {
+    for dir in start_dir.ancestors() {
+        let Ok(entries) = fs::read_dir(dir) else {
+            continue;
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if let Some(format) = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .and_then(lockfile_format_for)
+            {
+                return Some((path, format));
+            }
+        }
+    }
+
+    None
+}
+
+/// Walk upward from `start_dir` looking for a file named `file_name`.
+fn find_upward(start_dir: &Path, file_name: &str) -> Option<PathBuf> {
+    for dir in start_dir.ancestors() {
+        let candidate = dir.join(file_name);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+    }
+    None
+}
+
+/// Which yarn dialect a project's `--frozen-lockfile`/`--immutable` flag
+/// needs to match. Yarn Classic (1.x) only accepts `--frozen-lockfile`;
+/// Berry (>=2.0.0) only accepts `--immutable`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum YarnDialect {
+    Classic,
+    Berry,
+}
+
+/// Read the `packageManager` field from the nearest `package.json` (e.g.
+/// `"yarn@3.6.0"`) and classify it as Classic or Berry, using the same
+/// `>=2.0.0-0` boundary Turborepo uses to split the two. Returns `None`
+/// when no `packageManager` field names yarn, so either flag is accepted.
+fn detect_yarn_dialect(start_dir: &Path) -> Option<YarnDialect> {
+    let package_json = find_upward(start_dir, "package.json")?;
+    let content = fs::read_to_string(package_json).ok()?;
+    let root: Value = serde_json::from_str(&content).ok()?;
+    let package_manager = root.get("packageManager")?.as_str()?;
+    let version = Version::parse(package_manager.strip_prefix("yarn@")?).ok()?;
+
+    let berry_req = VersionReq::parse(">=2.0.0-0").unwrap();
+    Some(if berry_req.matches(&version) {
+        YarnDialect::Berry
+    } else {
+        YarnDialect::Classic
+    })
+}
+
+/// Parse a lockfile into a map of package name -> resolved version.
+fn parse_lockfile(path: &Path, format: &LockfileFormat) -> ResolvedVersions {
+    let Ok(content) = fs::read_to_string(path) else {
+        return ResolvedVersions::new();
+    };
+
+    match format {
+        LockfileFormat::Npm | LockfileFormat::Bun => parse_json_lockfile(&content),
+        LockfileFormat::Yarn => parse_yarn_lockfile(&content),
+        LockfileFormat::Pnpm => parse_pnpm_lockfile(&content),
+    }
+}
+
+/// Parse npm's `package-lock.json` and bun's (JSON) `bun.lock`. Handles both
+/// the modern `packages` map (lockfileVersion 2/3, keyed by
+/// `node_modules/<name>`) and the older flat `dependencies` map.
+fn parse_json_lockfile(content: &str) -> ResolvedVersions {
+    let mut resolved = ResolvedVersions::new();
+    let Ok(root) = serde_json::from_str::<Value>(content) else {
+        return resolved;
+    };
+
+    if let Some(packages) = root.get("packages").and_then(Value::as_object) {
+        // A package name can appear at multiple node_modules/ depths (nested
+        // transitive deps); prefer the shallowest one, since that's the
+        // version npm/bun would actually resolve a bare top-level pin to.
+        let mut depths: HashMap<&str, usize> = HashMap::new();
+        for (key, value) in packages {
+            let Some(name) = key.rsplit("node_modules/").next().filter(|n| !n.is_empty()) else {
+                continue;
+            };
+            let Some(version) = value.get("version").and_then(Value::as_str) else {
+                continue;
+            };
+
+            let depth = key.matches("node_modules/").count();
+            let is_shallower = match depths.get(name) {
+                Some(&existing) => depth < existing,
+                None => true,
+            };
+            if is_shallower {
+                depths.insert(name, depth);
+                resolved.insert(name.to_string(), version.to_string());
+            }
+        }
+    }
+
+    if let Some(dependencies) = root.get("dependencies").and_then(Value::as_object) {
+        for (name, value) in dependencies {
+            if let Some(version) = value.get("version").and_then(Value::as_str) {
+                resolved.entry(name.clone()).or_insert_with(|| version.to_string());
+            }
+        }
+    }
+
+    resolved
+}
+
+/// Parse a `yarn.lock`. Package headers are un-indented lines ending in
+/// `:` (possibly several comma-separated specs sharing one entry); the
+/// resolved version is the `version "x.y.z"` line indented underneath.
+fn parse_yarn_lockfile(content: &str) -> ResolvedVersions {
+    let mut resolved = ResolvedVersions::new();
+    let mut current_names: Vec<String> = Vec::new();
+
+    for line in content.lines() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if !line.starts_with(' ') && !line.starts_with('\t') {
+            current_names = line
+                .trim_end_matches(':')
+                .split(',')
+                .filter_map(|spec| {
+                    let spec = spec.trim().trim_matches('"');
+                    let (name, _) = split_package_spec(spec);
+                    (!name.is_empty()).then(|| name.to_string())
+                })
+                .collect();
+            continue;
+        }
+
+        if let Some(version) = line.trim().strip_prefix("version ") {
+            let version = version.trim().trim_matches('"').to_string();
+            for name in &current_names {
+                resolved.entry(name.clone()).or_insert_with(|| version.clone());
+            }
+        }
+    }
+
+    resolved
+}
+
+/// Parse a `pnpm-lock.yaml`. Resolved packages live under the top-level
+/// `packages:` key as indented `name@version:` (or `/name@version:`) entries.
+fn parse_pnpm_lockfile(content: &str) -> ResolvedVersions {
+    let mut resolved = ResolvedVersions::new();
+    let mut in_packages = false;
+
+    for line in content.lines() {
+        if line == "packages:" {
+            in_packages = true;
+            continue;
+        }
+
+        if !in_packages || line.trim().is_empty() {
+            continue;
+        }
+
+        if !line.starts_with(' ') {
+            in_packages = false;
+            continue;
+        }
+
+        let trimmed = line.trim().trim_end_matches(':').trim_matches('\'');
+        let key = trimmed.strip_prefix('/').unwrap_or(trimmed);
+        // Strip a trailing peer-dependency suffix, e.g. `(react@18.0.0)`,
+        // before splitting name/version — otherwise split_package_spec's
+        // last-`@` search lands inside the parens instead of at the real
+        // name/version boundary.
+        let key = key.split('(').next().unwrap_or(key);
+        let (name, spec) = split_package_spec(key);
+        if let Some(version) = spec {
+            if Version::parse(version).is_ok() {
+                resolved
+                    .entry(name.to_string())
+                    .or_insert_with(|| version.to_string());
+            }
+        }
+    }
+
+    resolved
+}
+
 #[derive(Debug)]
 struct Violation {
     line_num: usize,
@@ -13,12 +234,188 @@ struct Violation {
     line_content: String,
 }
 
+/// How a package's version spec reads once it's actually parsed as semver,
+/// rather than eyeballed with a regex.
+#[derive(Debug, PartialEq, Eq)]
+enum PinKind {
+    /// Fully specified `major.minor.patch`, optionally with an explicit `=`
+    /// operator and pre-release/build metadata (e.g. `1.2.3`, `=1.2.3-beta.1`).
+    Exact,
+    /// A semver range or wildcard (e.g. `^1.2.3`, `~1.2`, `>=2`, `1.x`, `*`).
+    Range,
+    /// Not parseable as a version or a range at all.
+    Invalid,
+}
+
+/// Tokens that show up in install/add command lines but aren't package
+/// arguments, so `check_package_pins` shouldn't try to classify them.
+const COMMAND_WORDS: &[&str] = &["npm", "pnpm", "yarn", "bun", "install", "i", "add", "ci", "global"];
+
+/// Split a package argument into its name and version spec, taking care
+/// that a leading `@` denotes an npm scope (`@scope/name`) rather than the
+/// start of the version.
+fn split_package_spec(token: &str) -> (&str, Option<&str>) {
+    let (scope_len, rest) = match token.strip_prefix('@') {
+        Some(rest) => (1, rest),
+        None => (0, token),
+    };
+
+    match rest.rfind('@') {
+        Some(idx) => {
+            let name_end = scope_len + idx;
+            (&token[..name_end], Some(&token[name_end + 1..]))
+        }
+        None => (token, None),
+    }
+}
+
+/// Classify a version spec the way `npm`/`pnpm`/`yarn`/`bun` would interpret
+/// it, using real semver parsing instead of a `@[0-9]+\.[0-9]+` regex.
+fn classify_version_spec(spec: &str) -> PinKind {
+    let spec = spec.trim();
+    if spec.is_empty() {
+        return PinKind::Invalid;
+    }
+
+    // An explicit `=` operator is still an exact pin.
+    let without_eq = spec.strip_prefix('=').unwrap_or(spec);
+    if Version::parse(without_eq).is_ok() {
+        return PinKind::Exact;
+    }
+
+    if VersionReq::parse(spec).is_ok() {
+        return PinKind::Range;
+    }
+
+    // `semver::VersionReq` doesn't understand npm's `x`/`*`/`||` range
+    // shorthand, so catch those by hand before giving up.
+    let has_wildcard_part = spec
+        .split(['.', ' '])
+        .any(|part| matches!(part.trim_start_matches(['^', '~', '=', '<', '>']), "x" | "X" | "*"));
+    if has_wildcard_part || spec.contains("||") {
+        return PinKind::Range;
+    }
+
+    PinKind::Invalid
+}
+
+/// Scan a command line for package arguments and flag any whose version
+/// spec isn't an exact semver pin.
+fn check_package_pins(
+    line: &str,
+    line_num: usize,
+    example_cmd: &str,
+    resolved: &ResolvedVersions,
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    for token in line.split_whitespace() {
+        let token = token.trim_end_matches(['&', ';', '|']);
+        if token.is_empty() || token.starts_with('-') || COMMAND_WORDS.contains(&token) {
+            continue;
+        }
+
+        let (name, spec) = split_package_spec(token);
+        match spec {
+            None => violations.push(Violation {
+                line_num,
+                message: format!(
+                    "{name} installed without version pin (use '{example_cmd} {name}@version')"
+                ),
+                line_content: line.trim().to_string(),
+            }),
+            Some(spec) => match classify_version_spec(spec) {
+                PinKind::Exact => {
+                    if let Some(violation) = check_lockfile_drift(name, spec, line_num, line, resolved) {
+                        violations.push(violation);
+                    }
+                }
+                PinKind::Range => violations.push(Violation {
+                    line_num,
+                    message: format!(
+                        "{name}@{spec} is a version range, not a pin; use an exact version"
+                    ),
+                    line_content: line.trim().to_string(),
+                }),
+                PinKind::Invalid => violations.push(Violation {
+                    line_num,
+                    message: format!("{name}@{spec} is not a valid semver version"),
+                    line_content: line.trim().to_string(),
+                }),
+            },
+        }
+    }
+
+    violations
+}
+
+/// Cross-validate an exact pin against a lockfile's resolved versions. Does
+/// nothing if no lockfile was found for this file (an empty map).
+fn check_lockfile_drift(
+    name: &str,
+    spec: &str,
+    line_num: usize,
+    line: &str,
+    resolved: &ResolvedVersions,
+) -> Option<Violation> {
+    if resolved.is_empty() {
+        return None;
+    }
+
+    // An explicit `=` operator (e.g. `=8.50.0`) is still the same version as
+    // far as the lockfile is concerned; strip it before comparing so it
+    // doesn't read as drift against a lockfile entry of `8.50.0`.
+    let normalized_spec = spec.strip_prefix('=').unwrap_or(spec);
+
+    match resolved.get(name) {
+        Some(locked_version) if locked_version != normalized_spec => Some(Violation {
+            line_num,
+            message: format!("pinned {name}@{spec} but lockfile has {name}@{locked_version}"),
+            line_content: line.trim().to_string(),
+        }),
+        Some(_) => None,
+        None => Some(Violation {
+            line_num,
+            message: format!("{name}@{spec} is not present in the lockfile"),
+            line_content: line.trim().to_string(),
+        }),
+    }
+}
+
 struct LintResult {
     violations_found: usize,
     files_checked: usize,
 }
 
+/// Whether to lint only, preview fixes, or rewrite files in place. Modeled
+/// on cargo-edit's `upgrade --dry-run` pair.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum FixMode {
+    Lint,
+    DryRun,
+    Apply,
+}
+
+fn parse_fix_mode(args: &[String]) -> FixMode {
+    if args.iter().any(|a| a == "--dry-run") {
+        FixMode::DryRun
+    } else if args.iter().any(|a| a == "--fix") {
+        FixMode::Apply
+    } else {
+        FixMode::Lint
+    }
+}
+
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match parse_fix_mode(&args) {
+        FixMode::Lint => run_lint(),
+        mode => run_fix(mode),
+    }
+}
+
+fn run_lint() {
     println!("{}", "Checking for JS package manager violations...\n".blue());
 
     // Collect all .gitignore files in the repository
@@ -102,7 +499,7 @@ fn lint_files(gitignores: &[(PathBuf, Gitignore)]) -> LintResult {
             files_checked = files_checked.saturating_add(1);
 
             if let Ok(content) = fs::read_to_string(path) {
-                let violations = check_file(&content);
+                let violations = check_file(&content, path);
 
                 if !violations.is_empty() {
                     // Skip reporting if file is in gitignore
@@ -167,10 +564,19 @@ fn should_check_file(path: &Path) -> bool {
     false
 }
 
-fn check_file(content: &str) -> Vec<Violation> {
+fn check_file(content: &str, path: &Path) -> Vec<Violation> {
     let mut violations = Vec::new();
     let mut in_code_block = false;
 
+    // Look for a lockfile once per file rather than once per line.
+    let resolved = path
+        .parent()
+        .and_then(find_lockfile)
+        .map(|(lockfile_path, format)| parse_lockfile(&lockfile_path, &format))
+        .unwrap_or_default();
+
+    let yarn_dialect = path.parent().and_then(detect_yarn_dialect);
+
     for (line_num, line) in content.lines().enumerate() {
         let line_num = line_num.saturating_add(1); // 1-indexed
 
@@ -191,10 +597,10 @@ fn check_file(content: &str) -> Vec<Violation> {
         }
 
         // Check all package managers
-        violations.extend(check_npm(line, line_num));
-        violations.extend(check_pnpm(line, line_num));
-        violations.extend(check_yarn(line, line_num));
-        violations.extend(check_bun(line, line_num));
+        violations.extend(check_npm(line, line_num, &resolved));
+        violations.extend(check_pnpm(line, line_num, &resolved));
+        violations.extend(check_yarn(line, line_num, &resolved, yarn_dialect));
+        violations.extend(check_bun(line, line_num, &resolved));
     }
 
     violations
@@ -210,7 +616,7 @@ fn is_comment_or_placeholder(line: &str) -> bool {
         || trimmed.starts_with('-')  // Skip markdown list items that are examples
 }
 
-fn check_npm(line: &str, line_num: usize) -> Vec<Violation> {
+fn check_npm(line: &str, line_num: usize, resolved: &ResolvedVersions) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     // Skip if it's pnpm, yarn, or bun (not npm)
@@ -226,12 +632,6 @@ fn check_npm(line: &str, line_num: usize) -> Vec<Violation> {
     // Check for npm install or npm i
     let npm_install_re = Regex::new(r"\bnpm\s+(install|i)(\s|$)").unwrap();
     if npm_install_re.is_match(line) {
-        // Check if it has a version pin
-        let version_pin_re = Regex::new(r"@[0-9]+\.[0-9]+").unwrap();
-        if version_pin_re.is_match(line) {
-            return violations; // Has version pin, allowed
-        }
-
         // Check if it's bare 'npm install' (should use npm ci)
         let bare_install_re = Regex::new(r"\bnpm\s+(install|i)(\s+)?($|&&|;|\||#)").unwrap();
         if bare_install_re.is_match(line) {
@@ -242,19 +642,14 @@ fn check_npm(line: &str, line_num: usize) -> Vec<Violation> {
                 line_content: line.trim().to_string(),
             });
         } else {
-            violations.push(Violation {
-                line_num,
-                message: "npm package installation without version pin (use 'npm i package@version')"
-                    .to_string(),
-                line_content: line.trim().to_string(),
-            });
+            violations.extend(check_package_pins(line, line_num, "npm i", resolved));
         }
     }
 
     violations
 }
 
-fn check_pnpm(line: &str, line_num: usize) -> Vec<Violation> {
+fn check_pnpm(line: &str, line_num: usize, resolved: &ResolvedVersions) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     // Check for pnpm install without --frozen-lockfile
@@ -273,31 +668,55 @@ fn check_pnpm(line: &str, line_num: usize) -> Vec<Violation> {
     // Check for pnpm add without version
     let pnpm_add_re = Regex::new(r"\bpnpm\s+add\s").unwrap();
     if pnpm_add_re.is_match(line) {
-        let version_pin_re = Regex::new(r"@[0-9]+\.[0-9]+").unwrap();
-        if !version_pin_re.is_match(line) {
-            violations.push(Violation {
-                line_num,
-                message: "pnpm package installation without version pin (use 'pnpm add package@version')"
-                    .to_string(),
-                line_content: line.trim().to_string(),
-            });
-        }
+        violations.extend(check_package_pins(line, line_num, "pnpm add", resolved));
     }
 
     violations
 }
 
-fn check_yarn(line: &str, line_num: usize) -> Vec<Violation> {
+fn check_yarn(
+    line: &str,
+    line_num: usize,
+    resolved: &ResolvedVersions,
+    yarn_dialect: Option<YarnDialect>,
+) -> Vec<Violation> {
     let mut violations = Vec::new();
 
-    // Check for yarn install or bare yarn without --frozen-lockfile or --immutable
-    let yarn_install_re = Regex::new(r"\byarn(\s+install)?(\s+)?($|&&|;|\||#)").unwrap();
+    // Check for yarn install or bare yarn, with or without a dialect flag already
+    // present. Only flag-like tokens (starting with '-') are allowed between the
+    // command and the terminator, so this doesn't swallow subcommands like
+    // `yarn add` or `yarn run` that are handled elsewhere.
+    let yarn_install_re =
+        Regex::new(r"\byarn(\s+install)?\b(\s+-\S+)*(\s+)?($|&&|;|\||#)").unwrap();
     if yarn_install_re.is_match(line) {
-        let frozen_re = Regex::new(r"--(frozen-lockfile|immutable)").unwrap();
-        if !frozen_re.is_match(line) {
+        let has_frozen_lockfile = Regex::new(r"--frozen-lockfile").unwrap().is_match(line);
+        let has_immutable = Regex::new(r"--immutable\b").unwrap().is_match(line);
+
+        let message = match yarn_dialect {
+            Some(YarnDialect::Berry) if has_frozen_lockfile => Some(
+                "Yarn Berry (>=2.0.0) uses '--immutable', not '--frozen-lockfile'".to_string(),
+            ),
+            Some(YarnDialect::Berry) if !has_immutable => Some(
+                "Use 'yarn install --immutable' to respect lockfile (Yarn Berry detected)"
+                    .to_string(),
+            ),
+            Some(YarnDialect::Classic) if has_immutable => Some(
+                "Yarn Classic (1.x) uses '--frozen-lockfile', not '--immutable'".to_string(),
+            ),
+            Some(YarnDialect::Classic) if !has_frozen_lockfile => Some(
+                "Use 'yarn install --frozen-lockfile' to respect lockfile (Yarn Classic detected)"
+                    .to_string(),
+            ),
+            None if !has_frozen_lockfile && !has_immutable => {
+                Some("Use 'yarn install --frozen-lockfile' to respect lockfile".to_string())
+            }
+            _ => None,
+        };
+
+        if let Some(message) = message {
             violations.push(Violation {
                 line_num,
-                message: "Use 'yarn install --frozen-lockfile' to respect lockfile".to_string(),
+                message,
                 line_content: line.trim().to_string(),
             });
         }
@@ -306,21 +725,13 @@ fn check_yarn(line: &str, line_num: usize) -> Vec<Violation> {
     // Check for yarn add without version
     let yarn_add_re = Regex::new(r"\byarn\s+(global\s+)?add\s").unwrap();
     if yarn_add_re.is_match(line) {
-        let version_pin_re = Regex::new(r"@[0-9]+\.[0-9]+").unwrap();
-        if !version_pin_re.is_match(line) {
-            violations.push(Violation {
-                line_num,
-                message: "yarn package installation without version pin (use 'yarn add package@version')"
-                    .to_string(),
-                line_content: line.trim().to_string(),
-            });
-        }
+        violations.extend(check_package_pins(line, line_num, "yarn add", resolved));
     }
 
     violations
 }
 
-fn check_bun(line: &str, line_num: usize) -> Vec<Violation> {
+fn check_bun(line: &str, line_num: usize, resolved: &ResolvedVersions) -> Vec<Violation> {
     let mut violations = Vec::new();
 
     // Check for bun install without --frozen-lockfile
@@ -339,15 +750,7 @@ fn check_bun(line: &str, line_num: usize) -> Vec<Violation> {
     // Check for bun add without version
     let bun_add_re = Regex::new(r"\bbun\s+add\s").unwrap();
     if bun_add_re.is_match(line) {
-        let version_pin_re = Regex::new(r"@[0-9]+\.[0-9]+").unwrap();
-        if !version_pin_re.is_match(line) {
-            violations.push(Violation {
-                line_num,
-                message: "bun package installation without version pin (use 'bun add package@version')"
-                    .to_string(),
-                line_content: line.trim().to_string(),
-            });
-        }
+        violations.extend(check_package_pins(line, line_num, "bun add", resolved));
     }
 
     violations
@@ -366,57 +769,285 @@ fn print_violations(path: &Path, violations: &[Violation]) {
     println!();
 }
 
+/// Compute a mechanical rewrite for one of the violation patterns this tool
+/// knows how to fix, or `None` if the line has no known fix.
+fn fix_line(line: &str, resolved: &ResolvedVersions, yarn_dialect: Option<YarnDialect>) -> Option<String> {
+    fix_bare_npm_install(line)
+        .or_else(|| fix_pnpm_install(line))
+        .or_else(|| fix_yarn_install(line, yarn_dialect))
+        .or_else(|| fix_bun_install(line))
+        .or_else(|| is_package_add_command(line).then(|| fix_unpinned_packages(line, resolved)).flatten())
+}
+
+fn is_package_add_command(line: &str) -> bool {
+    Regex::new(r"\bnpm\s+(install|i)\b").unwrap().is_match(line)
+        || Regex::new(r"\bpnpm\s+add\b").unwrap().is_match(line)
+        || Regex::new(r"\byarn\s+(global\s+)?add\b").unwrap().is_match(line)
+        || Regex::new(r"\bbun\s+add\b").unwrap().is_match(line)
+}
+
+/// `npm install`/`npm i` with no other package manager mentioned and no
+/// package arguments rewrites to `npm ci`.
+fn fix_bare_npm_install(line: &str) -> Option<String> {
+    if Regex::new(r"\b(pnpm|yarn|bun)\b").unwrap().is_match(line) {
+        return None;
+    }
+    if Regex::new(r"\bnpm\s+ci\b").unwrap().is_match(line) {
+        return None;
+    }
+
+    let bare_install_re = Regex::new(r"\bnpm\s+(install|i)(\s+)?($|&&|;|\||#)").unwrap();
+    if !bare_install_re.is_match(line) {
+        return None;
+    }
+
+    let command_re = Regex::new(r"\bnpm\s+(install|i)\b").unwrap();
+    Some(command_re.replace(line, "npm ci").to_string())
+}
+
+fn fix_pnpm_install(line: &str) -> Option<String> {
+    append_missing_flag(
+        line,
+        &Regex::new(r"\bpnpm\s+install\b").unwrap(),
+        &Regex::new(r"--frozen-lockfile").unwrap(),
+        "--frozen-lockfile",
+    )
+}
+
+fn fix_bun_install(line: &str) -> Option<String> {
+    append_missing_flag(
+        line,
+        &Regex::new(r"\bbun\s+install\b").unwrap(),
+        &Regex::new(r"--frozen-lockfile").unwrap(),
+        "--frozen-lockfile",
+    )
+}
+
+fn fix_yarn_install(line: &str, yarn_dialect: Option<YarnDialect>) -> Option<String> {
+    // Same broadened gate as check_yarn: match a yarn-install invocation whether
+    // or not a flag is already present, without swallowing subcommands like
+    // `yarn add`/`yarn run`.
+    let yarn_command_re =
+        Regex::new(r"\byarn(\s+install)?\b(\s+-\S+)*(\s+)?($|&&|;|\||#)").unwrap();
+    if !yarn_command_re.is_match(line) {
+        return None;
+    }
+
+    let (flag, wrong_flag_re) = match yarn_dialect {
+        Some(YarnDialect::Berry) => ("--immutable", Regex::new(r"--frozen-lockfile").unwrap()),
+        Some(YarnDialect::Classic) | None => {
+            ("--frozen-lockfile", Regex::new(r"--immutable\b").unwrap())
+        }
+    };
+
+    // If the wrong dialect's flag is already there, swap it for the right one
+    // instead of appending a second, conflicting flag. With no detected
+    // dialect either flag is accepted, so nothing is swapped.
+    if yarn_dialect.is_some() && wrong_flag_re.is_match(line) {
+        return Some(wrong_flag_re.replace(line, flag).to_string());
+    }
+
+    append_missing_flag(
+        line,
+        &Regex::new(r"\byarn(\s+install)?\b").unwrap(),
+        &Regex::new(r"--(frozen-lockfile|immutable)").unwrap(),
+        flag,
+    )
+}
+
+/// Insert `flag` right after the matched command, preserving the line's
+/// indentation and anything that follows (trailing `&&`/`;`/`|` included).
+fn append_missing_flag(
+    line: &str,
+    command_re: &Regex,
+    has_flag_re: &Regex,
+    flag: &str,
+) -> Option<String> {
+    if !command_re.is_match(line) || has_flag_re.is_match(line) {
+        return None;
+    }
+
+    let command_end = command_re.find(line)?.end();
+    let (before, after) = line.split_at(command_end);
+    Some(format!("{before} {flag}{after}"))
+}
+
+/// Inject a lockfile-resolved version into unpinned package arguments, e.g.
+/// `npm i eslint` -> `npm i eslint@8.50.0`. Leaves packages with no
+/// matching lockfile entry untouched.
+fn fix_unpinned_packages(line: &str, resolved: &ResolvedVersions) -> Option<String> {
+    if resolved.is_empty() {
+        return None;
+    }
+
+    let indent_len = line.len() - line.trim_start().len();
+    let (indent, rest) = line.split_at(indent_len);
+
+    let mut changed = false;
+    let fixed_words: Vec<String> = rest
+        .split_whitespace()
+        .map(|token| {
+            let trimmed = token.trim_end_matches(['&', ';', '|']);
+            let operator_suffix = &token[trimmed.len()..];
+
+            if trimmed.is_empty() || trimmed.starts_with('-') || COMMAND_WORDS.contains(&trimmed) {
+                return token.to_string();
+            }
+
+            let (name, spec) = split_package_spec(trimmed);
+            if spec.is_some() {
+                return token.to_string();
+            }
+
+            match resolved.get(name) {
+                Some(version) => {
+                    changed = true;
+                    format!("{name}@{version}{operator_suffix}")
+                }
+                None => token.to_string(),
+            }
+        })
+        .collect();
+
+    changed.then(|| format!("{indent}{}", fixed_words.join(" ")))
+}
+
+fn run_fix(mode: FixMode) {
+    let gitignores = collect_gitignores();
+    let mut files_changed: usize = 0;
+
+    for entry in WalkDir::new(".")
+        .into_iter()
+        .filter_entry(|e| !is_excluded(e.path()))
+        .filter_map(Result::ok)
+    {
+        let path = entry.path();
+        if !path.is_file() || !should_check_file(path) || is_ignored(path, &gitignores) {
+            continue;
+        }
+
+        let Ok(content) = fs::read_to_string(path) else {
+            continue;
+        };
+
+        let resolved = path
+            .parent()
+            .and_then(find_lockfile)
+            .map(|(lockfile_path, format)| parse_lockfile(&lockfile_path, &format))
+            .unwrap_or_default();
+        let yarn_dialect = path.parent().and_then(detect_yarn_dialect);
+
+        let mut in_code_block = false;
+        let mut file_changed = false;
+        let mut fixed_lines: Vec<String> = Vec::new();
+
+        for line in content.lines() {
+            if line.trim().starts_with("```") {
+                in_code_block = !in_code_block;
+                fixed_lines.push(line.to_string());
+                continue;
+            }
+
+            if in_code_block || is_comment_or_placeholder(line) {
+                fixed_lines.push(line.to_string());
+                continue;
+            }
+
+            match fix_line(line, &resolved, yarn_dialect) {
+                Some(fixed) if fixed != line => {
+                    file_changed = true;
+                    if mode == FixMode::DryRun {
+                        print_diff(path, line, &fixed);
+                    }
+                    fixed_lines.push(fixed);
+                }
+                _ => fixed_lines.push(line.to_string()),
+            }
+        }
+
+        if !file_changed {
+            continue;
+        }
+
+        files_changed = files_changed.saturating_add(1);
+
+        if mode == FixMode::Apply {
+            let mut new_content = fixed_lines.join("\n");
+            if content.ends_with('\n') {
+                new_content.push('\n');
+            }
+            if let Err(err) = fs::write(path, new_content) {
+                eprintln!("{}", format!("✗ failed to write {}: {err}", path.display()).red());
+            }
+        }
+    }
+
+    if mode == FixMode::DryRun {
+        println!();
+        println!("{}", format!("Dry run: {files_changed} file(s) would be fixed").blue());
+    } else {
+        println!("{}", format!("✓ Fixed {files_changed} file(s)").green());
+    }
+}
+
+fn print_diff(path: &Path, before: &str, after: &str) {
+    println!("{}", format!("~ {}", path.display()).yellow());
+    println!("  {} {}", "-".red(), before.trim());
+    println!("  {} {}", "+".green(), after.trim());
+    println!();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_npm_ci_allowed() {
-        let violations = check_npm("npm ci", 1);
+        let violations = check_npm("npm ci", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 0);
     }
 
     #[test]
     fn test_npm_install_bare_violation() {
-        let violations = check_npm("npm install", 1);
+        let violations = check_npm("npm install", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 1);
         assert!(violations[0].message.contains("npm ci"));
     }
 
     #[test]
     fn test_npm_install_with_version_allowed() {
-        let violations = check_npm("npm i eslint@8.50.0", 1);
+        let violations = check_npm("npm i eslint@8.50.0", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 0);
     }
 
     #[test]
     fn test_npm_install_without_version_violation() {
-        let violations = check_npm("npm i eslint", 1);
+        let violations = check_npm("npm i eslint", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 1);
         assert!(violations[0].message.contains("version pin"));
     }
 
     #[test]
     fn test_pnpm_install_frozen_allowed() {
-        let violations = check_pnpm("pnpm install --frozen-lockfile", 1);
+        let violations = check_pnpm("pnpm install --frozen-lockfile", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 0);
     }
 
     #[test]
     fn test_pnpm_install_violation() {
-        let violations = check_pnpm("pnpm install", 1);
+        let violations = check_pnpm("pnpm install", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 1);
     }
 
     #[test]
     fn test_yarn_frozen_allowed() {
-        let violations = check_yarn("yarn install --frozen-lockfile", 1);
+        let violations = check_yarn("yarn install --frozen-lockfile", 1, &ResolvedVersions::new(), None);
         assert_eq!(violations.len(), 0);
     }
 
     #[test]
     fn test_bun_add_with_version_allowed() {
-        let violations = check_bun("bun add react@18.2.0", 1);
+        let violations = check_bun("bun add react@18.2.0", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 0);
     }
 
@@ -433,7 +1064,7 @@ mod tests {
     #[test]
     fn test_npm_scoped_package_without_version_violation() {
         // Should flag @types/node without version
-        let violations = check_npm("npm i @types/node", 1);
+        let violations = check_npm("npm i @types/node", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 1);
         assert!(violations[0].message.contains("version pin"));
     }
@@ -441,14 +1072,14 @@ mod tests {
     #[test]
     fn test_npm_scoped_package_with_version_allowed() {
         // Should allow @types/node@18.0.0
-        let violations = check_npm("npm i @types/node@18.0.0", 1);
+        let violations = check_npm("npm i @types/node@18.0.0", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 0);
     }
 
     #[test]
     fn test_npm_scoped_org_package_without_version_violation() {
         // Should flag @myorg/privatepackage without version
-        let violations = check_npm("npm install @myorg/privatepackage", 1);
+        let violations = check_npm("npm install @myorg/privatepackage", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 1);
         assert!(violations[0].message.contains("version pin"));
     }
@@ -456,7 +1087,7 @@ mod tests {
     #[test]
     fn test_npm_scoped_org_package_with_version_allowed() {
         // Should allow @myorg/privatepackage@1.5.0
-        let violations = check_npm("npm install @myorg/privatepackage@1.5.0", 1);
+        let violations = check_npm("npm install @myorg/privatepackage@1.5.0", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 0);
     }
 
@@ -467,17 +1098,64 @@ mod tests {
     #[test]
     fn test_npm_full_semver_allowed() {
         // Should allow package@1.2.3
-        let violations = check_npm("npm i eslint@8.50.0", 1);
+        let violations = check_npm("npm i eslint@8.50.0", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 0);
     }
 
     #[test]
-    fn test_npm_short_semver_allowed() {
-        // Should allow package@1.2 (current regex matches this)
-        let violations = check_npm("npm i package@1.2", 1);
+    fn test_npm_short_semver_range_violation() {
+        // package@1.2 is a caret range once parsed as semver, not a pin
+        let violations = check_npm("npm i package@1.2", 1, &ResolvedVersions::new());
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("version range"));
+    }
+
+    #[test]
+    fn test_npm_caret_range_violation() {
+        let violations = check_npm("npm i eslint@^8.50.0", 1, &ResolvedVersions::new());
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("version range"));
+    }
+
+    #[test]
+    fn test_npm_invalid_spec_violation() {
+        let violations = check_npm("npm i eslint@latest", 1, &ResolvedVersions::new());
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("not a valid semver version"));
+    }
+
+    #[test]
+    fn test_npm_prerelease_exact_allowed() {
+        let violations = check_npm("npm i eslint@8.50.0-beta.1", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 0);
     }
 
+    // ===== classify_version_spec Tests =====
+    // Reference: https://docs.rs/semver/latest/semver/
+
+    #[test]
+    fn test_classify_exact() {
+        assert_eq!(classify_version_spec("1.2.3"), PinKind::Exact);
+        assert_eq!(classify_version_spec("=1.2.3"), PinKind::Exact);
+        assert_eq!(classify_version_spec("1.2.3-beta.1+meta"), PinKind::Exact);
+    }
+
+    #[test]
+    fn test_classify_range() {
+        assert_eq!(classify_version_spec("^1.2.3"), PinKind::Range);
+        assert_eq!(classify_version_spec("~1.2"), PinKind::Range);
+        assert_eq!(classify_version_spec(">=2"), PinKind::Range);
+        assert_eq!(classify_version_spec("1.x"), PinKind::Range);
+        assert_eq!(classify_version_spec("*"), PinKind::Range);
+        assert_eq!(classify_version_spec("a || b"), PinKind::Range);
+    }
+
+    #[test]
+    fn test_classify_invalid() {
+        assert_eq!(classify_version_spec("latest"), PinKind::Invalid);
+        assert_eq!(classify_version_spec(""), PinKind::Invalid);
+    }
+
     // ===== npm ci Tests =====
     // Reference: https://docs.npmjs.com/cli/v10/commands/npm-ci
     // npm ci can only install entire projects; individual dependencies cannot be added
@@ -485,7 +1163,7 @@ mod tests {
     #[test]
     fn test_npm_ci_bare_allowed() {
         // npm ci with no arguments is allowed
-        let violations = check_npm("npm ci", 1);
+        let violations = check_npm("npm ci", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 0);
     }
 
@@ -496,7 +1174,7 @@ mod tests {
     #[test]
     fn test_npm_dev_flag_without_version_violation() {
         // Should flag npm i -D eslint
-        let violations = check_npm("npm i -D eslint", 1);
+        let violations = check_npm("npm i -D eslint", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 1);
         assert!(violations[0].message.contains("version pin"));
     }
@@ -504,14 +1182,14 @@ mod tests {
     #[test]
     fn test_npm_dev_flag_with_version_allowed() {
         // Should allow npm i -D eslint@8.0.0
-        let violations = check_npm("npm i -D eslint@8.50.0", 1);
+        let violations = check_npm("npm i -D eslint@8.50.0", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 0);
     }
 
     #[test]
     fn test_npm_save_dev_flag_without_version_violation() {
         // Should flag npm install --save-dev typescript
-        let violations = check_npm("npm install --save-dev typescript", 1);
+        let violations = check_npm("npm install --save-dev typescript", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 1);
         assert!(violations[0].message.contains("version pin"));
     }
@@ -519,7 +1197,7 @@ mod tests {
     #[test]
     fn test_npm_save_dev_flag_with_version_allowed() {
         // Should allow npm install --save-dev typescript@5.0.0
-        let violations = check_npm("npm install --save-dev typescript@5.0.0", 1);
+        let violations = check_npm("npm install --save-dev typescript@5.0.0", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 0);
     }
 
@@ -528,27 +1206,27 @@ mod tests {
     
     #[test]
     fn test_pnpm_scoped_package_without_version_violation() {
-        let violations = check_pnpm("pnpm add @types/react", 1);
+        let violations = check_pnpm("pnpm add @types/react", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 1);
         assert!(violations[0].message.contains("version pin"));
     }
 
     #[test]
     fn test_pnpm_scoped_package_with_version_allowed() {
-        let violations = check_pnpm("pnpm add @types/react@18.0.0", 1);
+        let violations = check_pnpm("pnpm add @types/react@18.0.0", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 0);
     }
 
     #[test]
     fn test_pnpm_dev_flag_without_version_violation() {
-        let violations = check_pnpm("pnpm add -D vitest", 1);
+        let violations = check_pnpm("pnpm add -D vitest", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 1);
         assert!(violations[0].message.contains("version pin"));
     }
 
     #[test]
     fn test_pnpm_dev_flag_with_version_allowed() {
-        let violations = check_pnpm("pnpm add -D vitest@1.0.0", 1);
+        let violations = check_pnpm("pnpm add -D vitest@1.0.0", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 0);
     }
 
@@ -557,56 +1235,368 @@ mod tests {
     
     #[test]
     fn test_yarn_scoped_package_without_version_violation() {
-        let violations = check_yarn("yarn add @babel/core", 1);
+        let violations = check_yarn("yarn add @babel/core", 1, &ResolvedVersions::new(), None);
         assert_eq!(violations.len(), 1);
         assert!(violations[0].message.contains("version pin"));
     }
 
     #[test]
     fn test_yarn_scoped_package_with_version_allowed() {
-        let violations = check_yarn("yarn add @babel/core@7.22.0", 1);
+        let violations = check_yarn("yarn add @babel/core@7.22.0", 1, &ResolvedVersions::new(), None);
         assert_eq!(violations.len(), 0);
     }
 
     #[test]
     fn test_yarn_dev_flag_without_version_violation() {
-        let violations = check_yarn("yarn add -D jest", 1);
+        let violations = check_yarn("yarn add -D jest", 1, &ResolvedVersions::new(), None);
         assert_eq!(violations.len(), 1);
         assert!(violations[0].message.contains("version pin"));
     }
 
     #[test]
     fn test_yarn_dev_flag_with_version_allowed() {
-        let violations = check_yarn("yarn add -D jest@29.0.0", 1);
+        let violations = check_yarn("yarn add -D jest@29.0.0", 1, &ResolvedVersions::new(), None);
+        assert_eq!(violations.len(), 0);
+    }
+
+    // ===== Yarn Berry vs Classic Tests =====
+    // Reference: https://yarnpkg.com/migration/guide (--immutable replaces --frozen-lockfile in Berry)
+
+    #[test]
+    fn test_yarn_berry_frozen_lockfile_violation() {
+        let violations = check_yarn(
+            "yarn install --frozen-lockfile",
+            1,
+            &ResolvedVersions::new(),
+            Some(YarnDialect::Berry),
+        );
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("--immutable"));
+    }
+
+    #[test]
+    fn test_yarn_berry_immutable_allowed() {
+        let violations = check_yarn(
+            "yarn install --immutable",
+            1,
+            &ResolvedVersions::new(),
+            Some(YarnDialect::Berry),
+        );
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_yarn_classic_immutable_violation() {
+        let violations = check_yarn(
+            "yarn install --immutable",
+            1,
+            &ResolvedVersions::new(),
+            Some(YarnDialect::Classic),
+        );
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("--frozen-lockfile"));
+    }
+
+    #[test]
+    fn test_yarn_classic_frozen_lockfile_allowed() {
+        let violations = check_yarn(
+            "yarn install --frozen-lockfile",
+            1,
+            &ResolvedVersions::new(),
+            Some(YarnDialect::Classic),
+        );
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_yarn_no_dialect_accepts_either_flag() {
+        let berry_style = check_yarn(
+            "yarn install --immutable",
+            1,
+            &ResolvedVersions::new(),
+            None,
+        );
+        let classic_style = check_yarn(
+            "yarn install --frozen-lockfile",
+            1,
+            &ResolvedVersions::new(),
+            None,
+        );
+        assert_eq!(berry_style.len(), 0);
+        assert_eq!(classic_style.len(), 0);
+    }
+
+    #[test]
+    fn test_yarn_add_not_treated_as_install() {
+        let violations = check_yarn(
+            "yarn add eslint@8.50.0",
+            1,
+            &ResolvedVersions::new(),
+            Some(YarnDialect::Berry),
+        );
         assert_eq!(violations.len(), 0);
     }
 
+    #[test]
+    fn test_detect_yarn_dialect_berry() {
+        let dir = make_temp_dir("lockfile-guard-test-berry");
+        fs::write(dir.join("package.json"), r#"{"packageManager": "yarn@3.6.0"}"#).unwrap();
+        assert_eq!(detect_yarn_dialect(&dir), Some(YarnDialect::Berry));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_detect_yarn_dialect_classic() {
+        let dir = make_temp_dir("lockfile-guard-test-classic");
+        fs::write(dir.join("package.json"), r#"{"packageManager": "yarn@1.22.19"}"#).unwrap();
+        assert_eq!(detect_yarn_dialect(&dir), Some(YarnDialect::Classic));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_detect_yarn_dialect_missing_field() {
+        let dir = make_temp_dir("lockfile-guard-test-no-field");
+        fs::write(dir.join("package.json"), r#"{"name": "app"}"#).unwrap();
+        assert_eq!(detect_yarn_dialect(&dir), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fn make_temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("{name}-{:?}", std::thread::current().id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
     // ===== bun Tests =====
     // Reference: https://bun.sh/package-manager
     
     #[test]
     fn test_bun_scoped_package_without_version_violation() {
-        let violations = check_bun("bun add @hono/hono", 1);
+        let violations = check_bun("bun add @hono/hono", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 1);
         assert!(violations[0].message.contains("version pin"));
     }
 
     #[test]
     fn test_bun_scoped_package_with_version_allowed() {
-        let violations = check_bun("bun add @hono/hono@4.0.0", 1);
+        let violations = check_bun("bun add @hono/hono@4.0.0", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 0);
     }
 
     #[test]
     fn test_bun_dev_flag_without_version_violation() {
-        let violations = check_bun("bun add -D prettier", 1);
+        let violations = check_bun("bun add -D prettier", 1, &ResolvedVersions::new());
         assert_eq!(violations.len(), 1);
         assert!(violations[0].message.contains("version pin"));
     }
 
     #[test]
     fn test_bun_dev_flag_with_version_allowed() {
-        let violations = check_bun("bun add -D prettier@3.0.0", 1);
+        let violations = check_bun("bun add -D prettier@3.0.0", 1, &ResolvedVersions::new());
+        assert_eq!(violations.len(), 0);
+    }
+
+    // ===== Lockfile cross-validation Tests =====
+
+    #[test]
+    fn test_pin_matching_lockfile_allowed() {
+        let mut resolved = ResolvedVersions::new();
+        resolved.insert("eslint".to_string(), "8.50.0".to_string());
+        let violations = check_npm("npm i eslint@8.50.0", 1, &resolved);
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_pin_drifted_from_lockfile_violation() {
+        let mut resolved = ResolvedVersions::new();
+        resolved.insert("eslint".to_string(), "8.40.0".to_string());
+        let violations = check_npm("npm i eslint@8.50.0", 1, &resolved);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("but lockfile has eslint@8.40.0"));
+    }
+
+    #[test]
+    fn test_pin_missing_from_lockfile_violation() {
+        let mut resolved = ResolvedVersions::new();
+        resolved.insert("typescript".to_string(), "5.0.0".to_string());
+        let violations = check_npm("npm i eslint@8.50.0", 1, &resolved);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("not present in the lockfile"));
+    }
+
+    #[test]
+    fn test_no_lockfile_skips_cross_validation() {
+        let violations = check_npm("npm i eslint@8.50.0", 1, &ResolvedVersions::new());
+        assert_eq!(violations.len(), 0);
+    }
+
+    #[test]
+    fn test_parse_npm_lockfile_packages_map() {
+        let content = r#"{
+            "packages": {
+                "": {},
+                "node_modules/eslint": { "version": "8.50.0" },
+                "node_modules/@types/node": { "version": "18.0.0" }
+            }
+        }"#;
+        let resolved = parse_json_lockfile(content);
+        assert_eq!(resolved.get("eslint"), Some(&"8.50.0".to_string()));
+        assert_eq!(resolved.get("@types/node"), Some(&"18.0.0".to_string()));
+    }
+
+    #[test]
+    fn test_parse_yarn_lockfile_version_line() {
+        let content = "eslint@^8.50.0, eslint@^8.40.0:\n  version \"8.50.0\"\n  resolved \"...\"\n";
+        let resolved = parse_yarn_lockfile(content);
+        assert_eq!(resolved.get("eslint"), Some(&"8.50.0".to_string()));
+    }
+
+    #[test]
+    fn test_parse_pnpm_lockfile_version_line() {
+        let content = "packages:\n\n  /eslint@8.50.0:\n    resolution: {integrity: sha512-x}\n";
+        let resolved = parse_pnpm_lockfile(content);
+        assert_eq!(resolved.get("eslint"), Some(&"8.50.0".to_string()));
+    }
+
+    #[test]
+    fn test_parse_npm_lockfile_prefers_shallowest_duplicate() {
+        let content = r#"{
+            "packages": {
+                "": {},
+                "node_modules/eslint": { "version": "8.50.0" },
+                "node_modules/some-tool/node_modules/eslint": { "version": "6.0.0" }
+            }
+        }"#;
+        let resolved = parse_json_lockfile(content);
+        assert_eq!(resolved.get("eslint"), Some(&"8.50.0".to_string()));
+    }
+
+    #[test]
+    fn test_parse_pnpm_lockfile_strips_peer_dependency_suffix() {
+        let content = "packages:\n\n  react-dom@18.2.0(react@18.2.0):\n    resolution: {integrity: sha512-x}\n";
+        let resolved = parse_pnpm_lockfile(content);
+        assert_eq!(resolved.get("react-dom"), Some(&"18.2.0".to_string()));
+    }
+
+    #[test]
+    fn test_pin_with_explicit_eq_operator_matches_lockfile() {
+        let mut resolved = ResolvedVersions::new();
+        resolved.insert("eslint".to_string(), "8.50.0".to_string());
+        let violations = check_npm("npm i eslint@=8.50.0", 1, &resolved);
         assert_eq!(violations.len(), 0);
     }
+
+    // ===== Autofix Tests =====
+
+    #[test]
+    fn test_fix_bare_npm_install_to_ci() {
+        let fixed = fix_bare_npm_install("npm install").unwrap();
+        assert_eq!(fixed, "npm ci");
+    }
+
+    #[test]
+    fn test_fix_bare_npm_install_preserves_trailing_operator() {
+        let fixed = fix_bare_npm_install("  npm i && npm run build").unwrap();
+        assert_eq!(fixed, "  npm ci && npm run build");
+    }
+
+    #[test]
+    fn test_fix_bare_npm_install_skips_pinned_add() {
+        assert!(fix_bare_npm_install("npm i eslint@8.50.0").is_none());
+    }
+
+    #[test]
+    fn test_fix_pnpm_install_appends_flag() {
+        let fixed = fix_pnpm_install("  pnpm install").unwrap();
+        assert_eq!(fixed, "  pnpm install --frozen-lockfile");
+    }
+
+    #[test]
+    fn test_fix_pnpm_install_already_frozen_is_none() {
+        assert!(fix_pnpm_install("pnpm install --frozen-lockfile").is_none());
+    }
+
+    #[test]
+    fn test_fix_bun_install_appends_flag() {
+        let fixed = fix_bun_install("bun install && echo done").unwrap();
+        assert_eq!(fixed, "bun install --frozen-lockfile && echo done");
+    }
+
+    #[test]
+    fn test_fix_yarn_install_berry_appends_immutable() {
+        let fixed = fix_yarn_install("yarn install", Some(YarnDialect::Berry)).unwrap();
+        assert_eq!(fixed, "yarn install --immutable");
+    }
+
+    #[test]
+    fn test_fix_yarn_install_classic_appends_frozen_lockfile() {
+        let fixed = fix_yarn_install("yarn install", Some(YarnDialect::Classic)).unwrap();
+        assert_eq!(fixed, "yarn install --frozen-lockfile");
+    }
+
+    #[test]
+    fn test_fix_yarn_install_berry_replaces_frozen_lockfile() {
+        let fixed = fix_yarn_install("yarn install --frozen-lockfile", Some(YarnDialect::Berry))
+            .unwrap();
+        assert_eq!(fixed, "yarn install --immutable");
+    }
+
+    #[test]
+    fn test_fix_yarn_install_classic_replaces_immutable() {
+        let fixed =
+            fix_yarn_install("yarn install --immutable", Some(YarnDialect::Classic)).unwrap();
+        assert_eq!(fixed, "yarn install --frozen-lockfile");
+    }
+
+    #[test]
+    fn test_fix_yarn_install_ignores_add_command() {
+        assert_eq!(
+            fix_yarn_install("yarn add eslint@8.50.0", Some(YarnDialect::Berry)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_fix_unpinned_packages_injects_lockfile_version() {
+        let mut resolved = ResolvedVersions::new();
+        resolved.insert("eslint".to_string(), "8.50.0".to_string());
+        let fixed = fix_unpinned_packages("npm i eslint", &resolved).unwrap();
+        assert_eq!(fixed, "npm i eslint@8.50.0");
+    }
+
+    #[test]
+    fn test_fix_unpinned_packages_leaves_unknown_package() {
+        let mut resolved = ResolvedVersions::new();
+        resolved.insert("typescript".to_string(), "5.0.0".to_string());
+        assert!(fix_unpinned_packages("npm i eslint", &resolved).is_none());
+    }
+
+    #[test]
+    fn test_fix_unpinned_packages_no_lockfile_is_none() {
+        assert!(fix_unpinned_packages("npm i eslint", &ResolvedVersions::new()).is_none());
+    }
+
+    #[test]
+    fn test_fix_line_prefers_bare_install_over_pin_injection() {
+        let mut resolved = ResolvedVersions::new();
+        resolved.insert("eslint".to_string(), "8.50.0".to_string());
+        let fixed = fix_line("npm install", &resolved, None).unwrap();
+        assert_eq!(fixed, "npm ci");
+    }
+
+    #[test]
+    fn test_fix_line_no_fix_for_range_pin() {
+        assert!(fix_line("npm i eslint@^8.50.0", &ResolvedVersions::new(), None).is_none());
+    }
+
+    #[test]
+    fn test_parse_fix_mode() {
+        assert_eq!(parse_fix_mode(&[]), FixMode::Lint);
+        assert_eq!(parse_fix_mode(&["--fix".to_string()]), FixMode::Apply);
+        assert_eq!(parse_fix_mode(&["--dry-run".to_string()]), FixMode::DryRun);
+        assert_eq!(
+            parse_fix_mode(&["--fix".to_string(), "--dry-run".to_string()]),
+            FixMode::DryRun
+        );
+    }
 }